@@ -1,25 +1,145 @@
 use sysinfo::{self, MINIMUM_CPU_UPDATE_INTERVAL};
 use chrono::{self, DateTime, NaiveDateTime};
+use std::collections::VecDeque;
+use std::time::Duration;
+#[cfg(target_os = "windows")]
+use std::time::Instant;
 
 /* Constants  */
 const BYTES_PER_GB: u64 = 1024 * 1024 * 1024; // 1,073,741,824 bytes per gb. convert from b to gb
 const MHZ_TO_GHZ:   f64 = 0.001;              // number used when converting mhz frequency to ghz
 
+// fallback interval, in seconds, used for the Windows EWMA load average
+// on its first sample, when there is no previous tick to measure the
+// actual elapsed time from. every subsequent tick measures real elapsed
+// wall-clock time instead, since callers (e.g monitor_loop) may reload()
+// on any cadence they choose.
+const LOAD_AVG_SAMPLE_INTERVAL_SECS: f64 = 5.0;
+
+// max number of snapshots monitor_loop keeps around for recent_snapshots() queries
+const SNAPSHOT_HISTORY_CAPACITY: usize = 120;
+
+/**
+ * 1-, 5- and 15-minute load averages. Mirrors the Unix convention of
+ * "average number of runnable processes", even on platforms (Windows)
+ * that have no native concept of a load average.
+ */
+#[derive(Default, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LoadAvg {
+    pub one:     f64, // 1 minute load average
+    pub five:    f64, // 5 minute load average
+    pub fifteen: f64, // 15 minute load average
+}
+
 #[derive(Debug)]
 pub struct SysResources {
-    pub available_memory: u64,   // memory that can be used by the system (gb)
-    pub used_memory:      u64,   // memory in use by the system (gb)
-    pub total_memory:     u64,   // total memory installed in the system (gb)
+    pub metrics: Metrics, // gathered, serializable metric data
+
+    // private
+    system:  sysinfo::System,    // internal system struct used to gather info
+    history: VecDeque<Snapshot>, // bounded ring buffer of past snapshots, oldest first
+
+    // timestamp of the last Windows EWMA load-average sample. Unix reads
+    // the kernel load average directly, so it has no use for this.
+    #[cfg(target_os = "windows")]
+    last_load_average_sample: Option<Instant>,
+}
+
+/**
+ * All the metric data SysResources gathers, separated from the private
+ * sysinfo::System handle so it can be serialized on its own - the
+ * system handle itself does not implement Serialize.
+ */
+#[derive(Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Metrics {
+    pub available_memory: u64,   // memory that can be used by the system (bytes)
+    pub used_memory:      u64,   // memory in use by the system (bytes)
+    pub total_memory:     u64,   // total memory installed in the system (bytes)
+    pub buffers:          u64,   // memory held by kernel buffers (bytes)
+    pub cached_memory:    u64,   // page-cache memory, reclaimable on demand (bytes)
+    pub shared_memory:    u64,   // memory shared between processes, e.g tmpfs (bytes)
+    pub total_swap:       u64,   // total swap space configured (bytes)
+    pub used_swap:        u64,   // swap space currently in use (bytes)
+    pub free_swap:        u64,   // swap space currently free (bytes)
     pub boot_time:        u64,   // epoch time from when the system was booted
-    pub uptime:           u64,   // system uptime (days:hours:minutes:seconds)
+    pub uptime:           u64,   // system uptime (seconds)
     pub cpu:              CPU,   // struct containing information about the system cpu
     pub num_of_processes: usize, // number of running processes
+    pub load_average:     LoadAvg, // 1/5/15 minute run-queue load averages
+    pub processes:        Vec<Process>, // every running process on the system
+}
 
-    // private
-    system: sysinfo::System,     // internal system struct used to gather info
+/**
+ * An immutable point-in-time reading taken by monitor_loop(). Cheap to
+ * clone and store, unlike SysResources itself which owns the live
+ * sysinfo::System handle.
+ */
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Snapshot {
+    pub cpu_usage:        f32,     // global cpu usage as a percent
+    pub load_average:     LoadAvg, // 1/5/15 minute load averages
+    pub used_memory:      u64,     // used memory (bytes)
+    pub total_memory:     u64,     // total memory (bytes)
+    pub used_swap:        u64,     // used swap (bytes)
+    pub total_swap:       u64,     // total swap (bytes)
+    pub num_of_processes: usize,   // number of running processes
+}
+
+impl Snapshot {
+    // percentage of total memory currently in use at the time of this snapshot
+    pub fn memory_usage_percent(&self) -> f64 {
+        if self.total_memory == 0 {
+            return 0.0;
+        }
+        return self.used_memory as f64 / self.total_memory as f64 * 100.0;
+    }
+
+    // percentage of total swap currently in use at the time of this snapshot
+    pub fn swap_usage_percent(&self) -> f64 {
+        if self.total_swap == 0 {
+            return 0.0;
+        }
+        return self.used_swap as f64 / self.total_swap as f64 * 100.0;
+    }
+}
+
+/**
+ * A single running process on the system.
+ */
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Process {
+    pub pid:        u32,         // process id
+    pub parent_pid: Option<u32>, // process id of the parent process, if any
+    pub name:       String,      // name of the process, e.g chrome.exe
+    pub cpu_usage:  f32,         // usage of the cpu as a percent
+    pub memory:     u64,         // resident memory used by the process (bytes)
+    pub run_time:   u64,         // time the process has been running for (seconds)
+}
+
+impl Process {
+    /**
+     * Create a Process struct from a sysinfo internal
+     * 'Process' struct and its pid.
+     */
+    pub fn load_from_raw(pid: &sysinfo::Pid, raw_process: &sysinfo::Process) -> Self {
+        let mut process = Self::default();
+        process.pid = pid.as_u32();
+        process.parent_pid = raw_process.parent().map(|parent| parent.as_u32());
+        process.name = raw_process.name().to_string_lossy().into_owned();
+        process.cpu_usage = raw_process.cpu_usage();
+        process.memory = raw_process.memory();
+        process.run_time = raw_process.run_time();
+
+        return process
+    }
 }
 
 #[derive(Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct CPU {
     pub processes:  Vec<CPU>,  // logical processes, if any. if this is a logical processor it will be empty.
     pub cpu_usage:  f32,       // usage of the cpu or logical processor as a percent
@@ -50,12 +170,32 @@ impl CPU {
     }
 
     /**
-     * Get the frequency of a cpu (or logical processor) 
+     * Get the frequency of a cpu (or logical processor)
      * in Ghz, converting from Mhz.
      */
     pub fn get_cpu_frequency_ghz(&self) -> f64 {
         return &self.frequency * MHZ_TO_GHZ;
     }
+
+    /**
+     * Get the logical processor with the highest usage.
+     * Returns None if there are no logical processors loaded.
+     */
+    pub fn busiest_core(&self) -> Option<&CPU> {
+        return self.processes.iter().max_by(|a, b| a.cpu_usage.total_cmp(&b.cpu_usage));
+    }
+
+    /**
+     * Get the average usage across all logical processors.
+     * Returns 0.0 if there are no logical processors loaded.
+     */
+    pub fn average_core_usage(&self) -> f32 {
+        if self.processes.is_empty() {
+            return 0.0;
+        }
+        let total: f32 = self.processes.iter().map(|core| core.cpu_usage).sum();
+        return total / self.processes.len() as f32;
+    }
 }
 
 impl SysResources {    
@@ -65,17 +205,173 @@ impl SysResources {
      */
     pub fn new() -> SysResources {
         Self {
-            available_memory: 0,
-            used_memory: 0,
-            total_memory: 0,
-            boot_time: 0,
-            uptime: 0,
-            cpu: CPU::default(),
-            num_of_processes: 0,
-            system: sysinfo::System::new()
+            metrics: Metrics::default(),
+            system: sysinfo::System::new(),
+            history: VecDeque::new(),
+            #[cfg(target_os = "windows")]
+            last_load_average_sample: None
+        }
+    }
+
+    /**
+     * Serialize the gathered metrics to a JSON string. Only the
+     * Metrics sub-struct is serialized - the internal sysinfo::System
+     * handle isn't and doesn't need to be, since it holds no data
+     * callers care about beyond what's already copied into Metrics.
+     *
+     * Requires the `serde` feature, which must declare `serde` (with
+     * the "derive" feature) and `serde_json` as optional dependencies
+     * in Cargo.toml, e.g:
+     *
+     *   [dependencies]
+     *   serde      = { version = "1", features = ["derive"], optional = true }
+     *   serde_json = { version = "1", optional = true }
+     *
+     *   [features]
+     *   serde = ["dep:serde", "dep:serde_json"]
+     */
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        return serde_json::to_string(&self.metrics).unwrap_or_default();
+    }
+
+    /**
+     * Reload the system on a fixed cadence, handing an immutable
+     * Snapshot to the callback on every tick and recording it in the
+     * bounded history ring buffer. Reuses the internal system handle
+     * instead of rebuilding it every tick, unlike calling load() in a
+     * loop would.
+     *
+     * The callback returns false to stop the loop.
+     */
+    pub fn monitor_loop<F>(&mut self, interval: Duration, mut callback: F)
+    where
+        F: FnMut(&Snapshot) -> bool
+    {
+        loop {
+            self.reload();
+
+            let snapshot = self.take_snapshot();
+            self.push_snapshot(snapshot.clone());
+
+            if !callback(&snapshot) {
+                break;
+            }
+
+            std::thread::sleep(interval);
         }
     }
 
+    /**
+     * Get the last n snapshots recorded by monitor_loop, oldest first.
+     * Returns fewer than n if not enough history has been collected yet.
+     */
+    pub fn recent_snapshots(&self, n: usize) -> Vec<&Snapshot> {
+        let skip = self.history.len().saturating_sub(n);
+        return self.history.iter().skip(skip).collect()
+    }
+
+    fn take_snapshot(&self) -> Snapshot {
+        Snapshot {
+            cpu_usage: self.metrics.cpu.cpu_usage,
+            load_average: self.metrics.load_average,
+            used_memory: self.metrics.used_memory,
+            total_memory: self.metrics.total_memory,
+            used_swap: self.metrics.used_swap,
+            total_swap: self.metrics.total_swap,
+            num_of_processes: self.metrics.num_of_processes
+        }
+    }
+
+    fn push_snapshot(&mut self, snapshot: Snapshot) {
+        if self.history.len() == SNAPSHOT_HISTORY_CAPACITY {
+            self.history.pop_front();
+        }
+        self.history.push_back(snapshot);
+    }
+
+    /**
+     * Get the n heaviest processes by CPU usage, heaviest first.
+     */
+    pub fn top_by_cpu(&self, n: usize) -> Vec<&Process> {
+        let mut sorted: Vec<&Process> = self.metrics.processes.iter().collect();
+        sorted.sort_by(|a, b| b.cpu_usage.total_cmp(&a.cpu_usage));
+        sorted.truncate(n);
+
+        return sorted
+    }
+
+    /**
+     * Get the n heaviest processes by resident memory, heaviest first.
+     */
+    pub fn top_by_memory(&self, n: usize) -> Vec<&Process> {
+        let mut sorted: Vec<&Process> = self.metrics.processes.iter().collect();
+        sorted.sort_by(|a, b| b.memory.cmp(&a.memory));
+        sorted.truncate(n);
+
+        return sorted
+    }
+
+    /**
+     * Rebuild the process list from the internal system handle.
+     *
+     * Relies on the caller (reload_cpu_info) having already performed
+     * the settled, two-refresh sample pass needed for non-zero process
+     * cpu_usage() - refreshing again here would just repeat the same
+     * MINIMUM_CPU_UPDATE_INTERVAL sleep for no benefit.
+     */
+    fn reload_processes(&mut self) {
+        self.metrics.processes = self.system.processes().iter()
+            .map(|(pid, raw_process)| Process::load_from_raw(pid, raw_process))
+            .collect();
+    }
+
+    /**
+     * Get the 1-, 5- and 15-minute load averages.
+     *
+     * On Unix this comes straight from the kernel. Windows has no
+     * native load-average facility, so it is estimated from periodic
+     * CPU usage samples gathered during reload() using the same EWMA
+     * recurrence the Linux kernel uses internally.
+     */
+    pub fn get_load_average(&self) -> LoadAvg {
+        return self.metrics.load_average
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    fn reload_load_average(&mut self) {
+        let raw = sysinfo::System::load_average();
+        self.metrics.load_average = LoadAvg {
+            one: raw.one,
+            five: raw.five,
+            fifteen: raw.fifteen
+        };
+    }
+
+    #[cfg(target_os = "windows")]
+    fn reload_load_average(&mut self) {
+        // measure actual elapsed time since the last sample rather than
+        // assuming a fixed cadence - callers (e.g monitor_loop) may
+        // reload() on whatever interval they like.
+        let elapsed_secs = self.last_load_average_sample
+            .map(|last| last.elapsed().as_secs_f64())
+            .unwrap_or(LOAD_AVG_SAMPLE_INTERVAL_SECS);
+        self.last_load_average_sample = Some(Instant::now());
+
+        let exp_one     = (-elapsed_secs / 60.0).exp();
+        let exp_five    = (-elapsed_secs / 300.0).exp();
+        let exp_fifteen = (-elapsed_secs / 900.0).exp();
+
+        // number of busy logical processors, as a continuous fraction
+        let active_fraction: f64 = self.metrics.cpu.processes.iter()
+            .map(|core| core.cpu_usage as f64 / 100.0)
+            .sum();
+
+        self.metrics.load_average.one     = self.metrics.load_average.one     * exp_one     + active_fraction * (1.0 - exp_one);
+        self.metrics.load_average.five    = self.metrics.load_average.five    * exp_five    + active_fraction * (1.0 - exp_five);
+        self.metrics.load_average.fifteen = self.metrics.load_average.fifteen * exp_fifteen + active_fraction * (1.0 - exp_fifteen);
+    }
+
     /**
      * Return the percentage of the CPU
      * usage.
@@ -84,25 +380,36 @@ impl SysResources {
         self.system.refresh_cpu_usage();
         std::thread::sleep(MINIMUM_CPU_UPDATE_INTERVAL);
         self.system.refresh_cpu_usage();
-        self.cpu.cpu_usage = self.system.global_cpu_usage();
+        self.metrics.cpu.cpu_usage = self.system.global_cpu_usage();
 
-        return self.cpu.cpu_usage
+        return self.metrics.cpu.cpu_usage
     }
 
     /**
      * Populate fields in SysResources with 
      * information about the system
      */
-    pub fn load(&mut self) { 
+    pub fn load(&mut self) {
         self.system = sysinfo::System::new_all();
-        self.available_memory = self.system.available_memory();
-        self.used_memory      = self.system.used_memory();
-        self.boot_time        = sysinfo::System::boot_time();
-        self.uptime           = sysinfo::System::uptime();
-        self.num_of_processes = self.system.processes().len();
-        self.total_memory     = self.system.total_memory();
-        
+        self.metrics.available_memory = self.system.available_memory();
+        self.metrics.used_memory      = self.system.used_memory();
+        self.metrics.boot_time        = sysinfo::System::boot_time();
+        self.metrics.uptime           = sysinfo::System::uptime();
+        self.metrics.num_of_processes = self.system.processes().len();
+        self.metrics.total_memory     = self.system.total_memory();
+        self.metrics.total_swap       = self.system.total_swap();
+        self.metrics.used_swap        = self.system.used_swap();
+        self.metrics.free_swap        = self.system.free_swap();
+        self.reload_memory_breakdown();
+
         self.load_cpu_info();
+
+        // cpu and process usage are both diff-based and read as 0.0
+        // until a settled, two-refresh sample pass has run - reload_cpu_info
+        // performs that pass (and reloads processes/load average off the
+        // back of it), so load() defers to it instead of reading process
+        // data straight after System::new_all().
+        self.reload_cpu_info();
     }
 
     /**
@@ -112,33 +419,121 @@ impl SysResources {
      */
     pub fn reload(&mut self) {
         self.system.refresh_all();
-        self.uptime = sysinfo::System::uptime();
-        self.available_memory = self.system.available_memory();
-        self.used_memory = self.system.used_memory();
-        self.num_of_processes = self.system.processes().len();
+        self.metrics.uptime = sysinfo::System::uptime();
+        self.metrics.available_memory = self.system.available_memory();
+        self.metrics.used_memory = self.system.used_memory();
+        self.metrics.num_of_processes = self.system.processes().len();
+        self.metrics.total_swap = self.system.total_swap();
+        self.metrics.used_swap  = self.system.used_swap();
+        self.metrics.free_swap  = self.system.free_swap();
+        self.reload_memory_breakdown();
         self.reload_cpu_info();
     }
 
     // used memory in bytes. divide by BYTES_PER_GB to get gb
-    pub fn used_memory_gb(&self) -> u64 {
-        return self.used_memory / BYTES_PER_GB; 
+    pub fn used_memory_gb(&self) -> f64 {
+        return self.metrics.used_memory as f64 / BYTES_PER_GB as f64;
     }
 
     // avaiable memory in bytes. divide by BYTES_PER_GB to get gb
-    pub fn available_memory_gb(&self) -> u64 {
-        return self.available_memory / BYTES_PER_GB; 
+    pub fn available_memory_gb(&self) -> f64 {
+        return self.metrics.available_memory as f64 / BYTES_PER_GB as f64;
     }
 
     // total memory in bytes. divide by BYTES_PER_GB to get gb
-    pub fn total_memory_gb(&self) -> u64 {
-        return self.total_memory / BYTES_PER_GB;    
+    pub fn total_memory_gb(&self) -> f64 {
+        return self.metrics.total_memory as f64 / BYTES_PER_GB as f64;
+    }
+
+    // buffer memory in bytes. divide by BYTES_PER_GB to get gb
+    pub fn buffers_gb(&self) -> f64 {
+        return self.metrics.buffers as f64 / BYTES_PER_GB as f64;
+    }
+
+    // page-cache memory in bytes. divide by BYTES_PER_GB to get gb
+    pub fn cached_memory_gb(&self) -> f64 {
+        return self.metrics.cached_memory as f64 / BYTES_PER_GB as f64;
+    }
+
+    // shared memory in bytes. divide by BYTES_PER_GB to get gb
+    pub fn shared_memory_gb(&self) -> f64 {
+        return self.metrics.shared_memory as f64 / BYTES_PER_GB as f64;
+    }
+
+    // total swap in bytes. divide by BYTES_PER_GB to get gb
+    pub fn total_swap_gb(&self) -> f64 {
+        return self.metrics.total_swap as f64 / BYTES_PER_GB as f64;
+    }
+
+    // used swap in bytes. divide by BYTES_PER_GB to get gb
+    pub fn used_swap_gb(&self) -> f64 {
+        return self.metrics.used_swap as f64 / BYTES_PER_GB as f64;
+    }
+
+    // free swap in bytes. divide by BYTES_PER_GB to get gb
+    pub fn free_swap_gb(&self) -> f64 {
+        return self.metrics.free_swap as f64 / BYTES_PER_GB as f64;
+    }
+
+    /**
+     * Percentage of total physical memory currently in use.
+     */
+    pub fn memory_usage_percent(&self) -> f64 {
+        if self.metrics.total_memory == 0 {
+            return 0.0;
+        }
+        return self.metrics.used_memory as f64 / self.metrics.total_memory as f64 * 100.0;
+    }
+
+    /**
+     * Percentage of total swap space currently in use.
+     */
+    pub fn swap_usage_percent(&self) -> f64 {
+        if self.metrics.total_swap == 0 {
+            return 0.0;
+        }
+        return self.metrics.used_swap as f64 / self.metrics.total_swap as f64 * 100.0;
+    }
+
+    /**
+     * Populate buffers, page-cache and shared-memory figures.
+     *
+     * sysinfo's cross-platform System struct only distinguishes
+     * "free" from "available" memory, so the finer breakdown the
+     * platform kernel actually tracks is read directly from
+     * /proc/meminfo on Linux. Other platforms don't expose these
+     * categories, so the fields stay at 0.
+     */
+    #[cfg(target_os = "linux")]
+    fn reload_memory_breakdown(&mut self) {
+        let Ok(contents) = std::fs::read_to_string("/proc/meminfo") else {
+            return;
+        };
+
+        for line in contents.lines() {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let kb: u64 = value.trim().trim_end_matches(" kB").trim().parse().unwrap_or(0);
+            let bytes = kb * 1024;
+
+            match key {
+                "Buffers" => self.metrics.buffers = bytes,
+                "Cached"  => self.metrics.cached_memory = bytes,
+                "Shmem"   => self.metrics.shared_memory = bytes,
+                _ => {}
+            }
+        }
     }
 
+    #[cfg(not(target_os = "linux"))]
+    fn reload_memory_breakdown(&mut self) {}
+
     /**
      * Get systems uptime in local time
      */
     pub fn get_uptime(&self) -> Option<NaiveDateTime> {
-        let time =  DateTime::from_timestamp(self.uptime as i64, 0);
+        let time =  DateTime::from_timestamp(self.metrics.uptime as i64, 0);
         match time {
             Some(utc) => return Some(utc.naive_local()),
             None => {}
@@ -151,7 +546,7 @@ impl SysResources {
      * in local time.
      */
     pub fn get_boot_time(&self) -> Option<NaiveDateTime> {
-        let time =  DateTime::from_timestamp(self.boot_time as i64, 0);
+        let time =  DateTime::from_timestamp(self.metrics.boot_time as i64, 0);
         match time {
             Some(utc) => return Some(utc.naive_local()),
             None => {}
@@ -171,13 +566,13 @@ impl SysResources {
         
         let raw_cpu: &sysinfo::Cpu = &self.system.cpus()[0];
         
-        self.cpu.brand      = raw_cpu.brand().to_string();
-        self.cpu.core_count = self.system.physical_core_count().unwrap_or(0);
-        self.cpu.cpu_usage  = raw_cpu.cpu_usage();
-        self.cpu.frequency  = raw_cpu.frequency() as f64;
-        self.cpu.name       = raw_cpu.name().to_string();
-        self.cpu.vendor_id  = raw_cpu.vendor_id().to_string();
-        self.cpu.processes  = self.system.cpus().iter().map(CPU::load_from_raw).collect();
+        self.metrics.cpu.brand      = raw_cpu.brand().to_string();
+        self.metrics.cpu.core_count = self.system.physical_core_count().unwrap_or(0);
+        self.metrics.cpu.cpu_usage  = raw_cpu.cpu_usage();
+        self.metrics.cpu.frequency  = raw_cpu.frequency() as f64;
+        self.metrics.cpu.name       = raw_cpu.name().to_string();
+        self.metrics.cpu.vendor_id  = raw_cpu.vendor_id().to_string();
+        self.metrics.cpu.processes  = self.system.cpus().iter().map(CPU::load_from_raw).collect();
     }
     
     /**
@@ -185,14 +580,19 @@ impl SysResources {
      * of the logical processes belonging to the
      * cpu. Information is saved directly to
      * the CPU struct.
+     *
+     * Relies on the caller (reload_cpu_info) having already performed
+     * the settled, two-refresh sample pass needed for non-zero usage -
+     * refreshing again here would just repeat the same
+     * MINIMUM_CPU_UPDATE_INTERVAL sleep for no benefit.
      */
     fn reload_cpu_cores(&mut self) {
         let cores = self.system.cpus();
-    
+
         // load info about cpu cores
         for (index, core ) in cores.iter().enumerate() {
             // update info using index
-            if let Some(saved_cpu_core) = self.cpu.processes.get_mut(index) {
+            if let Some(saved_cpu_core) = self.metrics.cpu.processes.get_mut(index) {
                 saved_cpu_core.cpu_usage = core.cpu_usage();
                 saved_cpu_core.frequency = core.frequency() as f64;
             }
@@ -203,15 +603,28 @@ impl SysResources {
      * Reload informationa about the CPU
      * that changes. Fields that dont change
      * like brand or vendor ID are not refreshed.
-     * 
+     *
      * load_cpu_info should be called first.
+     *
+     * CPU usage and process usage are both diff-based and only become
+     * non-zero after a settled, two-refresh sample pass - share a single
+     * MINIMUM_CPU_UPDATE_INTERVAL sleep between them here instead of
+     * paying it twice per reload().
      */
     fn reload_cpu_info(&mut self) {
-        self.get_cpu_usage();
-        
+        self.system.refresh_cpu_usage();
+        self.system.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+        std::thread::sleep(MINIMUM_CPU_UPDATE_INTERVAL);
+        self.system.refresh_cpu_usage();
+        self.system.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+
+        self.metrics.cpu.cpu_usage = self.system.global_cpu_usage();
+
         let raw_cpu = self.system.cpus().get(0).unwrap();
-        
-        self.cpu.frequency  = raw_cpu.frequency() as f64;
+
+        self.metrics.cpu.frequency  = raw_cpu.frequency() as f64;
         self.reload_cpu_cores();
+        self.reload_processes();
+        self.reload_load_average();
     }
 }
\ No newline at end of file
@@ -3,31 +3,91 @@ mod api {
     pub mod monitor;
 }
 
+use std::time::Duration;
+
+// interval monitor_loop reloads on in --watch mode
+const WATCH_INTERVAL: Duration = Duration::from_secs(2);
+
+// number of ticks --watch samples before printing its history summary
+const WATCH_TICKS: usize = 5;
+
 fn main() {
     let mut rsrc = api::monitor::SysResources::new();
     rsrc.load();
+    rsrc.reload(); // take one more full sample on top of the one load() already took
+
+    #[cfg(feature = "serde")]
+    if std::env::args().any(|arg| arg == "--json") {
+        println!("{}", rsrc.to_json());
+        return;
+    }
+
+    if std::env::args().any(|arg| arg == "--watch") {
+        let mut remaining_ticks = WATCH_TICKS;
+        rsrc.monitor_loop(WATCH_INTERVAL, |snapshot| {
+            println!(
+                "CPU {:>5.1}% | Load {:.2}, {:.2}, {:.2} | Mem {:>5.1}% | Swap {:>5.1}% | Processes {}",
+                snapshot.cpu_usage,
+                snapshot.load_average.one, snapshot.load_average.five, snapshot.load_average.fifteen,
+                snapshot.memory_usage_percent(),
+                snapshot.swap_usage_percent(),
+                snapshot.num_of_processes,
+            );
+
+            remaining_ticks -= 1;
+            return remaining_ticks > 0;
+        });
+
+        println!();
+        println!("History ({} snapshots retained)", rsrc.recent_snapshots(WATCH_TICKS).len());
+        for snapshot in rsrc.recent_snapshots(WATCH_TICKS) {
+            println!("    CPU {:>5.1}% | Processes {}", snapshot.cpu_usage, snapshot.num_of_processes);
+        }
+        return;
+    }
 
     println!("CPU Information");
     println!("Product Details");
-    println!("Brand:     {}", rsrc.cpu.brand);
-    println!("Vendor ID: {}", rsrc.cpu.vendor_id);
-    println!("Frequency: {:.2} GHz", rsrc.cpu.get_cpu_frequency_ghz());
-    println!("Cores:     {}", rsrc.cpu.core_count);
+    println!("Brand:     {}", rsrc.metrics.cpu.brand);
+    println!("Vendor ID: {}", rsrc.metrics.cpu.vendor_id);
+    println!("Frequency: {:.2} GHz", rsrc.metrics.cpu.get_cpu_frequency_ghz());
+    println!("Cores:     {}", rsrc.metrics.cpu.core_count);
     println!();
     println!("Performance Details");
     println!("CPU Usage:         {} %", rsrc.get_cpu_usage() );
-    println!("Running Processes: {}", rsrc.num_of_processes);
-    println!("Total Memory:      {} GB", rsrc.total_memory_gb());
-    println!("Available:         {} GB", rsrc.available_memory_gb());
-    println!("Used:              {} GB", rsrc.used_memory_gb());
-    println!("Logical Processors ({}):", rsrc.cpu.processes.len());
-    for (index, core) in rsrc.cpu.processes.iter().enumerate() {
-        if index > 2 {
-            println!("    ... (truncated)");
-            break; 
-        }
+    let load_avg = rsrc.get_load_average();
+    println!("Load Average (1, 5, 15 min): {:.2}, {:.2}, {:.2}", load_avg.one, load_avg.five, load_avg.fifteen);
+    println!("Running Processes: {}", rsrc.metrics.num_of_processes);
+    println!("Total Memory:      {:.2} GB", rsrc.total_memory_gb());
+    println!("Available:         {:.2} GB", rsrc.available_memory_gb());
+    println!("Used:              {:.2} GB ({:.1}%)", rsrc.used_memory_gb(), rsrc.memory_usage_percent());
+    println!("Buffers:           {:.2} GB", rsrc.buffers_gb());
+    println!("Cached:            {:.2} GB", rsrc.cached_memory_gb());
+    println!("Shared:            {:.2} GB", rsrc.shared_memory_gb());
+    println!("Swap Total:        {:.2} GB", rsrc.total_swap_gb());
+    println!("Swap Used:         {:.2} GB ({:.1}%)", rsrc.used_swap_gb(), rsrc.swap_usage_percent());
+    println!("Logical Processors ({}):", rsrc.metrics.cpu.processes.len());
+    for core in rsrc.metrics.cpu.processes.iter() {
         println!("    {}: {} MHz - Usage: {}% ", core.name, core.frequency, core.cpu_usage);
     }
+    println!("Average Core Usage: {:.1}%", rsrc.metrics.cpu.average_core_usage());
+    if let Some(busiest) = rsrc.metrics.cpu.busiest_core() {
+        println!("Busiest Core:       {} ({:.1}%)", busiest.name, busiest.cpu_usage);
+    }
+
+    println!();
+
+    println!("Top 5 Processes (by CPU)");
+    for process in rsrc.top_by_cpu(5) {
+        println!("    {:<24} pid {:<8} {:>6.1}% {:>8.2} MB", process.name, process.pid, process.cpu_usage, process.memory as f64 / 1024.0 / 1024.0);
+    }
+
+    println!();
+
+    println!("Top 5 Processes (by memory)");
+    for process in rsrc.top_by_memory(5) {
+        println!("    {:<24} pid {:<8} {:>6.1}% {:>8.2} MB", process.name, process.pid, process.cpu_usage, process.memory as f64 / 1024.0 / 1024.0);
+    }
 
     println!();
 